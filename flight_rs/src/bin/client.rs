@@ -0,0 +1,83 @@
+// Client-side harness: connects to the benchmark server, issues do_get, and decodes the
+// resulting FlightData stream the way a real consumer would (via FlightRecordBatchStream),
+// so the decode path gets measured independently of the server's encode path.
+
+use arrow_flight::decode::FlightRecordBatchStream;
+use arrow_flight::flight_service_client::FlightServiceClient;
+use arrow_flight::Ticket;
+use futures::TryStreamExt;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut client = FlightServiceClient::connect("http://[::1]:50051").await?;
+
+    let ticket = Ticket {
+        ticket: Vec::new().into(),
+    };
+
+    let start = Instant::now();
+    let flight_data_stream = client.do_get(ticket).await?.into_inner();
+    // Arrival time of the most recent FlightData message, so a batch's decode time runs from the
+    // arrival of its last message to the decoder yielding it, excluding network wait.
+    let last_message_at = Arc::new(Mutex::new(start));
+    let arrival = last_message_at.clone();
+    let mut decoder = FlightRecordBatchStream::new_from_flight_data(
+        flight_data_stream
+            .map_ok(move |data| {
+                *arrival.lock().unwrap() = Instant::now();
+                data
+            })
+            .map_err(|status| status.into()),
+    );
+
+    let mut first_batch_latency = None;
+    let mut first_batch_rows = 0usize;
+    let mut batch_count = 0usize;
+    let mut total_rows = 0usize;
+    let mut peak_batch_bytes = 0usize;
+    let mut last_batch_at = start;
+
+    while let Some(batch) = decoder.try_next().await? {
+        let now = Instant::now();
+        if first_batch_latency.is_none() {
+            first_batch_latency = Some(now.duration_since(start));
+            first_batch_rows = batch.num_rows();
+        }
+
+        let decode_time = now.duration_since(*last_message_at.lock().unwrap());
+        last_batch_at = now;
+
+        batch_count += 1;
+        total_rows += batch.num_rows();
+        peak_batch_bytes = peak_batch_bytes.max(batch.get_array_memory_size());
+
+        println!(
+            "batch {:>4}: {:>7} rows, decoded in {:>7.2?}",
+            batch_count,
+            batch.num_rows(),
+            decode_time
+        );
+    }
+
+    let total_elapsed = start.elapsed();
+    // Steady state: rows after the first batch, over the time from the first batch to the last.
+    let steady_elapsed =
+        last_batch_at.duration_since(start) - first_batch_latency.unwrap_or_default();
+    let rows_per_sec = if steady_elapsed.as_secs_f64() > 0.0 {
+        (total_rows - first_batch_rows) as f64 / steady_elapsed.as_secs_f64()
+    } else {
+        0.0
+    };
+
+    println!("--- summary ---");
+    println!("batches:              {}", batch_count);
+    println!("rows:                 {}", total_rows);
+    println!("first batch latency:  {:?}", first_batch_latency.unwrap_or_default());
+    println!("total time:           {:?}", total_elapsed);
+    println!("steady-state rows/s:  {:.2}", rows_per_sec);
+    println!("peak batch memory:    {} bytes", peak_batch_bytes);
+
+    Ok(())
+}
@@ -1,33 +1,376 @@
+use arrow_flight::decode::FlightRecordBatchStream;
 use arrow_flight::encode::FlightDataEncoderBuilder;
-use futures::{StreamExt, TryStreamExt};
+use arrow_flight::error::FlightError;
+use futures::{Stream, StreamExt, TryStreamExt};
 use futures::stream::{BoxStream, self};
 use tonic::transport::Server;
 use tonic::{Request, Response, Status, Streaming};
+use std::pin::Pin;
 use std::str::FromStr;
+use std::task::{Context, Poll};
+use std::time::Instant;
 
 use arrow_flight::{
     flight_service_server::FlightService, flight_service_server::FlightServiceServer, Action,
-    ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo, HandshakeRequest,
-    HandshakeResponse, PollInfo, PutResult, SchemaResult, Ticket,
+    ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PollInfo, PutResult, SchemaAsIpc, SchemaResult, Ticket,
 };
-use arrow::array::{ArrayRef, Int32Array};
-use arrow::datatypes::{DataType, Field, Schema};
+use arrow_ipc::writer::IpcWriteOptions;
+use bytes::Bytes;
+use arrow::array::{
+    ArrayRef, BooleanBuilder, Float64Builder, Int32Array, Int32Builder, Int64Builder,
+    StringBuilder, StringDictionaryBuilder,
+};
+use arrow::datatypes::{DataType, Field, Int32Type, Schema};
 use arrow::record_batch::RecordBatch;
 use std::env;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
 
 /*
-    configurable env variables - 
+    configurable env variables -
 
     NUM_ROWS --> number of rows to hold in memory and to send to client
     NUM_COLUMNS --> number of columns to hold in memory and to send to client
     MAX_ROWS --> maximum number of rows in a single record batch (chunking)
+    MAX_FLIGHT_DATA_SIZE --> target serialized size (bytes) per FlightData message, used by the
+                             byte-size chunking path in do_get (default 2 MiB)
+    SCHEMA_PROFILE --> column-type template used to generate the dataset: "int32" (default),
+                       "mixed", "strings", "dictionary", or "wide-nullable" (see SchemaProfile)
+    STRING_AVG_LEN --> average string length generated by the "strings" profile (default 16)
+    DICTIONARY_CARDINALITY --> number of distinct values generated by the "dictionary" profile
+                               (default 8)
+    FLIGHT_TRACE --> when set to "1", emit a per-message tracing span from do_get and log a
+                     p50/p95/p99 encode-latency summary once each do_get stream is drained
 */
 
+// Column-type template selected via SCHEMA_PROFILE, so a benchmark run can compare how the
+// Flight encoder behaves across Arrow types rather than only ever seeing plain Int32 columns.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SchemaProfile {
+    Int32,
+    Mixed,
+    Strings,
+    Dictionary,
+    WideNullable,
+}
+
+impl SchemaProfile {
+    // Env-var bootstrap path: an unset/unrecognized SCHEMA_PROFILE silently falls back to the
+    // default profile rather than failing server startup.
+    fn from_env() -> Self {
+        Self::try_parse(&env::var("SCHEMA_PROFILE").unwrap_or_default()).unwrap_or(SchemaProfile::Int32)
+    }
+
+    // Reconfigure control-plane path: a typo'd profile name must fail the request instead of
+    // silently switching the dataset to a different profile mid-sweep.
+    fn try_parse(value: &str) -> Result<Self, String> {
+        match value {
+            "int32" => Ok(SchemaProfile::Int32),
+            "mixed" => Ok(SchemaProfile::Mixed),
+            "strings" => Ok(SchemaProfile::Strings),
+            "dictionary" => Ok(SchemaProfile::Dictionary),
+            "wide-nullable" => Ok(SchemaProfile::WideNullable),
+            other => Err(format!("unknown schema_profile: {other}")),
+        }
+    }
+}
+
+// Names of the datasets advertised by list_flights/get_flight_info and selected via
+// FlightDescriptor.cmd / Ticket bytes. "ingested" replays whatever do_put has accumulated.
+const DATASET_LARGE_BATCH: &str = "large_batch";
+const DATASET_CHUNKED: &str = "chunked";
+const DATASET_INGESTED: &str = "ingested";
+const DATASETS: [&str; 3] = [DATASET_LARGE_BATCH, DATASET_CHUNKED, DATASET_INGESTED];
+
+// do_action types making up the runtime control plane.
+const ACTION_RECONFIGURE: &str = "reconfigure";
+const ACTION_STATS: &str = "stats";
+const ACTION_RESET_STATS: &str = "reset_stats";
+
+// The generated datasets plus the parameters they were generated from. Held behind a RwLock so
+// `reconfigure` can regenerate everything in place without restarting the server.
+struct GeneratedData {
+    num_rows: usize,
+    num_columns: usize,
+    max_rows: usize,
+    schema_profile: SchemaProfile,
+    large_batch: RecordBatch,
+    chunked_bathes: Vec<RecordBatch>,
+}
+
+impl GeneratedData {
+    fn generate(num_rows: usize, num_columns: usize, max_rows: usize, schema_profile: SchemaProfile) -> Result<Self, arrow::error::ArrowError> {
+        Ok(GeneratedData {
+            num_rows,
+            num_columns,
+            max_rows,
+            schema_profile,
+            large_batch: generate_record_batch(num_columns, num_rows, schema_profile)?,
+            chunked_bathes: generate_record_batches(num_columns, num_rows, max_rows, schema_profile)?,
+        })
+    }
+}
+
+// Counters updated on every do_get message, so the `stats` action can report the last run's
+// timing/byte counters. `calls` counts every do_get since the last `reset_stats`; the other
+// fields are reset at the start of each do_get.
+#[derive(Default)]
+struct DoGetStats {
+    calls: u64,
+    messages_sent: u64,
+    bytes_sent: u64,
+    last_duration: Duration,
+    encode_durations: Vec<Duration>,
+}
+
+impl DoGetStats {
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"calls\":{},\"messages_sent\":{},\"bytes_sent\":{},\"last_duration_millis\":{}}}",
+            self.calls,
+            self.messages_sent,
+            self.bytes_sent,
+            self.last_duration.as_millis()
+        )
+    }
+}
+
+// Percentile over a set of encode-latency samples, taking the sorted sample whose index is
+// p% of (len - 1) rounded to the nearest integer; used for the FLIGHT_TRACE per-run summary.
+// Returns Duration::ZERO for an empty sample set.
+fn percentile(samples: &[Duration], p: f64) -> Duration {
+    if samples.is_empty() {
+        return Duration::ZERO;
+    }
+
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+
+    let rank = ((p / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+    sorted[rank.min(sorted.len() - 1)]
+}
+
 #[derive(Clone)]
 pub struct FlightServiceImpl {
-    large_batch: RecordBatch,
-    chunked_bathes: Vec<RecordBatch>
+    state: Arc<RwLock<GeneratedData>>,
+    // Batches accumulated by do_put when the caller's FlightDescriptor.cmd asks for them to be
+    // kept around, so a later do_get can replay whatever was ingested.
+    ingested: Arc<Mutex<Vec<RecordBatch>>>,
+    stats: Arc<Mutex<DoGetStats>>,
+}
+
+impl FlightServiceImpl {
+    // The schema `dataset` actually encodes: do_put accepts arbitrary incoming FlightData, so
+    // "ingested" may hold a different schema than the currently-configured generated dataset.
+    // Falls back to the generated schema when nothing has been ingested yet.
+    fn schema_for(&self, dataset: &str) -> Schema {
+        if dataset == DATASET_INGESTED {
+            if let Some(first) = self.ingested.lock().unwrap().first() {
+                return first.schema().as_ref().clone();
+            }
+        }
+        self.state.read().unwrap().large_batch.schema().as_ref().clone()
+    }
+
+    // Shared by get_flight_info and list_flights: builds the FlightInfo for whichever dataset
+    // the descriptor's cmd names (defaulting to the pre-chunked dataset for an empty/unknown cmd).
+    // Returns a boxed Status since tonic::Status is large relative to the FlightInfo success case.
+    fn flight_info_for(&self, descriptor: FlightDescriptor) -> Result<FlightInfo, Box<Status>> {
+        let dataset = if descriptor.cmd.as_ref() == DATASET_LARGE_BATCH.as_bytes() {
+            DATASET_LARGE_BATCH
+        } else if descriptor.cmd.as_ref() == DATASET_INGESTED.as_bytes() {
+            DATASET_INGESTED
+        } else {
+            DATASET_CHUNKED
+        };
+
+        let (total_records, total_bytes) = {
+            let state = self.state.read().unwrap();
+            if dataset == DATASET_LARGE_BATCH {
+                (
+                    state.large_batch.num_rows() as i64,
+                    state.large_batch.get_array_memory_size() as i64,
+                )
+            } else if dataset == DATASET_INGESTED {
+                let ingested = self.ingested.lock().unwrap();
+                let rows: usize = ingested.iter().map(|b| b.num_rows()).sum();
+                let bytes: usize = ingested.iter().map(|b| b.get_array_memory_size()).sum();
+                (rows as i64, bytes as i64)
+            } else {
+                let rows: usize = state.chunked_bathes.iter().map(|b| b.num_rows()).sum();
+                let bytes: usize = state.chunked_bathes.iter().map(|b| b.get_array_memory_size()).sum();
+                (rows as i64, bytes as i64)
+            }
+        };
+
+        let schema = schema_as_ipc(&self.schema_for(dataset))?;
+
+        let endpoint = FlightEndpoint {
+            ticket: Some(Ticket {
+                ticket: dataset.as_bytes().to_vec().into(),
+            }),
+            location: vec![],
+            expiration_time: None,
+            app_metadata: Bytes::new(),
+        };
+
+        Ok(FlightInfo {
+            schema,
+            flight_descriptor: Some(descriptor),
+            endpoint: vec![endpoint],
+            total_records,
+            total_bytes,
+            ordered: false,
+            app_metadata: Bytes::new(),
+        })
+    }
+
+    // Regenerates large_batch/chunked_bathes in place from a reconfigure action's parameters,
+    // falling back to the currently configured value for any parameter that was left unset.
+    // Returns a boxed Status since tonic::Status is large relative to the () success case.
+    fn reconfigure(&self, params: ReconfigureParams) -> Result<(), Box<Status>> {
+        let mut state = self.state.write().unwrap();
+
+        let num_rows = params.num_rows.unwrap_or(state.num_rows);
+        let num_columns = params.num_columns.unwrap_or(state.num_columns);
+        let max_rows = params.max_rows.unwrap_or(state.max_rows);
+        let schema_profile = params.schema_profile.unwrap_or(state.schema_profile);
+
+        *state = GeneratedData::generate(num_rows, num_columns, max_rows, schema_profile)
+            .map_err(|e| Box::new(Status::internal(e.to_string())))?;
+
+        Ok(())
+    }
+}
+
+// Parameters accepted by the "reconfigure" action, encoded in the action body as
+// "key=value&key=value" (e.g. "num_rows=100000&schema_profile=mixed"). Unset keys leave the
+// corresponding server parameter unchanged.
+#[derive(Default)]
+struct ReconfigureParams {
+    num_rows: Option<usize>,
+    num_columns: Option<usize>,
+    max_rows: Option<usize>,
+    schema_profile: Option<SchemaProfile>,
+}
+
+impl ReconfigureParams {
+    fn parse(body: &[u8]) -> Result<Self, String> {
+        let text = std::str::from_utf8(body).map_err(|e| e.to_string())?;
+        let mut params = ReconfigureParams::default();
+
+        for pair in text.split('&').filter(|s| !s.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("malformed reconfigure parameter: {pair}"))?;
+
+            match key {
+                "num_rows" => params.num_rows = Some(value.parse().map_err(|e: std::num::ParseIntError| e.to_string())?),
+                "num_columns" => params.num_columns = Some(value.parse().map_err(|e: std::num::ParseIntError| e.to_string())?),
+                "max_rows" => params.max_rows = Some(value.parse().map_err(|e: std::num::ParseIntError| e.to_string())?),
+                "schema_profile" => params.schema_profile = Some(SchemaProfile::try_parse(value)?),
+                other => return Err(format!("unknown reconfigure parameter: {other}")),
+            }
+        }
+
+        Ok(params)
+    }
+}
+
+// Encodes an Arrow schema as the IPC-format bytes Flight expects in FlightInfo::schema and
+// SchemaResult::schema. Returns a boxed Status since tonic::Status is large relative to the
+// Bytes success case.
+fn schema_as_ipc(schema: &Schema) -> Result<Bytes, Box<Status>> {
+    let options = IpcWriteOptions::default();
+    let message: arrow_flight::IpcMessage = SchemaAsIpc::new(schema, &options)
+        .try_into()
+        .map_err(|e: arrow::error::ArrowError| Box::new(Status::internal(e.to_string())))?;
+    Ok(message.0)
+}
+
+// Wraps do_get's output stream to record, per emitted FlightData message: the poll-to-ready
+// time (an approximation of encode duration, since encoding happens inside the inner stream's
+// poll_next), the serialized byte size, and the time since the previous message. Every message
+// updates the shared `stats` (so the `stats` action reports the most recent call's totals), and
+// also accumulates into `call_*` fields scoped to this stream alone, so that when `trace` is set
+// the final per-run summary reports this call's own numbers even if another do_get has since
+// started and reset the shared counters.
+struct InstrumentedDoGetStream<S> {
+    inner: S,
+    stats: Arc<Mutex<DoGetStats>>,
+    start: Instant,
+    last_message_at: Instant,
+    trace: bool,
+    call_messages_sent: u64,
+    call_bytes_sent: u64,
+    call_encode_durations: Vec<Duration>,
+}
+
+impl<S> Stream for InstrumentedDoGetStream<S>
+where
+    S: Stream<Item = Result<FlightData, Status>> + Unpin,
+{
+    type Item = Result<FlightData, Status>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let poll_start = Instant::now();
+        let poll = Pin::new(&mut this.inner).poll_next(cx);
+
+        match &poll {
+            Poll::Ready(Some(Ok(data))) => {
+                let now = Instant::now();
+                let encode_duration = now.duration_since(poll_start);
+                let since_previous = now.duration_since(this.last_message_at);
+                this.last_message_at = now;
+
+                let bytes = data.data_header.len() + data.data_body.len();
+
+                if this.trace {
+                    let span = tracing::debug_span!(
+                        "flight_data_message",
+                        bytes,
+                        encode_micros = encode_duration.as_micros() as u64,
+                        since_previous_micros = since_previous.as_micros() as u64,
+                    );
+                    let _enter = span.enter();
+                    tracing::debug!("emitted FlightData message");
+                }
+
+                this.call_messages_sent += 1;
+                this.call_bytes_sent += bytes as u64;
+                this.call_encode_durations.push(encode_duration);
+
+                let mut stats = this.stats.lock().unwrap();
+                stats.messages_sent += 1;
+                stats.bytes_sent += bytes as u64;
+                stats.last_duration = now.duration_since(this.start);
+                stats.encode_durations.push(encode_duration);
+            }
+            Poll::Ready(None) if this.trace => {
+                let total_elapsed = this.start.elapsed();
+                let bandwidth_mb_s = if total_elapsed.as_secs_f64() > 0.0 {
+                    (this.call_bytes_sent as f64 / (1024.0 * 1024.0)) / total_elapsed.as_secs_f64()
+                } else {
+                    0.0
+                };
+
+                tracing::info!(
+                    messages = this.call_messages_sent,
+                    total_bytes = this.call_bytes_sent,
+                    p50_encode_micros = percentile(&this.call_encode_durations, 50.0).as_micros() as u64,
+                    p95_encode_micros = percentile(&this.call_encode_durations, 95.0).as_micros() as u64,
+                    p99_encode_micros = percentile(&this.call_encode_durations, 99.0).as_micros() as u64,
+                    bandwidth_mb_per_sec = bandwidth_mb_s,
+                    "do_get stream drained",
+                );
+            }
+            _ => {}
+        }
+
+        poll
+    }
 }
 
 #[tonic::async_trait]
@@ -51,14 +394,22 @@ impl FlightService for FlightServiceImpl {
         &self,
         _request: Request<Criteria>,
     ) -> Result<Response<Self::ListFlightsStream>, Status> {
-        Err(Status::unimplemented("Implement list_flights"))
+        let mut infos = Vec::new();
+        for dataset in DATASETS {
+            let descriptor = FlightDescriptor::new_cmd(dataset.as_bytes().to_vec());
+            infos.push(self.flight_info_for(descriptor).map_err(|e| *e)?);
+        }
+
+        Ok(Response::new(Box::pin(stream::iter(infos.into_iter().map(Ok)))))
     }
 
     async fn get_flight_info(
         &self,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        Err(Status::unimplemented("Implement get_flight_info"))
+        let descriptor = request.into_inner();
+        let info = self.flight_info_for(descriptor).map_err(|e| *e)?;
+        Ok(Response::new(info))
     }
 
     async fn poll_flight_info(
@@ -70,47 +421,199 @@ impl FlightService for FlightServiceImpl {
 
     async fn get_schema(
         &self,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<SchemaResult>, Status> {
-        Err(Status::unimplemented("Implement get_schema"))
+        let descriptor = request.into_inner();
+        let dataset = if descriptor.cmd.as_ref() == DATASET_INGESTED.as_bytes() {
+            DATASET_INGESTED
+        } else {
+            DATASET_CHUNKED
+        };
+        let ipc = schema_as_ipc(&self.schema_for(dataset)).map_err(|e| *e)?;
+        Ok(Response::new(SchemaResult { schema: ipc }))
     }
 
     async fn do_get(
         &self,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<Self::DoGetStream>, Status> {
 
-        // IF YOU WISH TO SEND ONE RECORD BATCH:
-        // let size = self.large_batch.num_rows();
-        // let batches = vec![self.large_batch.slice(0, size)];
+        // Ticket bytes select both which dataset to serve and which chunking strategy to use, so
+        // a single server can be used to compare "large_batch" against "chunked" against
+        // "ingested", and "rows per batch" against "bytes per message" throughput:
+        //   "large_batch"        --> the unchunked dataset, handed to the encoder as one batch
+        //   "chunked" / ""       --> the pre-chunked dataset, in batches of at most MAX_ROWS rows
+        //   "ingested"           --> whatever do_put has accumulated, in the batches it arrived in
+        //   "<dataset>+bytes"    --> the same dataset, split by MAX_FLIGHT_DATA_SIZE instead
+        //   "bytes"              --> shorthand for "chunked+bytes", kept for convenience
+        // Without "+bytes" the encoder keeps its own default per-message size target, so a batch
+        // larger than that target is still split by the encoder.
+        let ticket = request.into_inner();
+        let ticket_str = String::from_utf8_lossy(&ticket.ticket);
+
+        let (dataset, bytes_mode) = if ticket_str.as_ref() == "bytes" {
+            (DATASET_CHUNKED, true)
+        } else if let Some(dataset) = ticket_str.strip_suffix("+bytes") {
+            (dataset, true)
+        } else {
+            (ticket_str.as_ref(), false)
+        };
+
+        let batches: Vec<RecordBatch> = if dataset == DATASET_LARGE_BATCH {
+            let state = self.state.read().unwrap();
+            let size = state.large_batch.num_rows();
+            vec![state.large_batch.slice(0, size)]
+        } else if dataset == DATASET_INGESTED {
+            let ingested = self.ingested.lock().unwrap();
+            ingested.iter().map(|b| b.slice(0, b.num_rows())).collect()
+        } else {
+            let state = self.state.read().unwrap();
+            state.chunked_bathes.iter().map(|b| b.slice(0, b.num_rows())).collect()
+        };
+
+        let builder = if bytes_mode {
+            FlightDataEncoderBuilder::new().with_max_flight_data_size(max_flight_data_size())
+        } else {
+            FlightDataEncoderBuilder::new()
+        };
 
-        let batches: Vec<RecordBatch> = self.chunked_bathes.iter().map(|b| b.slice(0, b.num_rows())).collect();
         let stream = stream::iter(batches).map(Ok);
-
-        let fd = FlightDataEncoderBuilder::new().build(stream).map_err(|e| Status::internal(e.to_string()));
-
-        Ok(Response::new(Box::pin(fd)))
+        let fd = builder.build(stream).map_err(|e| Status::internal(e.to_string()));
+
+        {
+            // Each do_get starts the message/byte counters afresh, so `stats` reports this call.
+            let mut stats = self.stats.lock().unwrap();
+            stats.calls += 1;
+            stats.messages_sent = 0;
+            stats.bytes_sent = 0;
+            stats.encode_durations.clear();
+        }
+        let start = Instant::now();
+        let instrumented = InstrumentedDoGetStream {
+            inner: Box::pin(fd),
+            stats: self.stats.clone(),
+            start,
+            last_message_at: start,
+            trace: env::var("FLIGHT_TRACE").as_deref() == Ok("1"),
+            call_messages_sent: 0,
+            call_bytes_sent: 0,
+            call_encode_durations: Vec::new(),
+        };
+
+        Ok(Response::new(Box::pin(instrumented)))
     }
 
     async fn do_put(
         &self,
-        _request: Request<Streaming<FlightData>>,
+        request: Request<Streaming<FlightData>>,
     ) -> Result<Response<Self::DoPutStream>, Status> {
-        Err(Status::unimplemented("Implement do_put"))
+        let start = Instant::now();
+
+        let messages: Vec<FlightData> = request
+            .into_inner()
+            .try_collect()
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        // Model the command dispatch on Flight SQL's CommandStatementIngest: an empty cmd on the
+        // first message's descriptor means "count and discard" (pure throughput mode), any other
+        // cmd means "accumulate" so a later do_get can replay what was ingested.
+        let accumulate = messages
+            .first()
+            .and_then(|fd| fd.flight_descriptor.as_ref())
+            .map(|d| !d.cmd.is_empty())
+            .unwrap_or(false);
+
+        let total_bytes: usize = messages
+            .iter()
+            .map(|fd| fd.data_header.len() + fd.data_body.len())
+            .sum();
+
+        let decoded: Vec<RecordBatch> =
+            FlightRecordBatchStream::new_from_flight_data(stream::iter(messages.into_iter().map(Ok::<_, FlightError>)))
+                .try_collect()
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+
+        let total_rows: usize = decoded.iter().map(|b| b.num_rows()).sum();
+        let total_batches = decoded.len();
+
+        if accumulate {
+            self.ingested.lock().unwrap().extend(decoded);
+        }
+
+        let elapsed = start.elapsed();
+        let rows_per_sec = if elapsed.as_secs_f64() > 0.0 {
+            total_rows as f64 / elapsed.as_secs_f64()
+        } else {
+            0.0
+        };
+
+        let app_metadata = format!(
+            "{{\"rows\":{},\"bytes\":{},\"batches\":{},\"millis\":{},\"rows_per_sec\":{:.2}}}",
+            total_rows,
+            total_bytes,
+            total_batches,
+            elapsed.as_millis(),
+            rows_per_sec
+        );
+
+        let result = PutResult {
+            app_metadata: app_metadata.into_bytes().into(),
+        };
+
+        Ok(Response::new(Box::pin(stream::iter(vec![Ok(result)]))))
     }
 
     async fn do_action(
         &self,
-        _request: Request<Action>,
+        request: Request<Action>,
     ) -> Result<Response<Self::DoActionStream>, Status> {
-        Err(Status::unimplemented("Implement do_action"))
+        let action = request.into_inner();
+
+        match action.r#type.as_str() {
+            ACTION_RECONFIGURE => {
+                let params = ReconfigureParams::parse(&action.body).map_err(Status::invalid_argument)?;
+                self.reconfigure(params).map_err(|e| *e)?;
+                Ok(Response::new(Box::pin(stream::empty())))
+            }
+            ACTION_STATS => {
+                let body = self.stats.lock().unwrap().to_json();
+                let result = arrow_flight::Result {
+                    body: body.into_bytes().into(),
+                };
+                Ok(Response::new(Box::pin(stream::iter(vec![Ok(result)]))))
+            }
+            ACTION_RESET_STATS => {
+                *self.stats.lock().unwrap() = DoGetStats::default();
+                Ok(Response::new(Box::pin(stream::empty())))
+            }
+            other => Err(Status::unimplemented(format!("unknown action type: {other}"))),
+        }
     }
 
     async fn list_actions(
         &self,
         _request: Request<Empty>,
     ) -> Result<Response<Self::ListActionsStream>, Status> {
-        Err(Status::unimplemented("Implement list_actions"))
+        let actions = vec![
+            ActionType {
+                r#type: ACTION_RECONFIGURE.to_string(),
+                description: "Regenerate the datasets in place from a \"key=value&...\" body \
+                    (num_rows, num_columns, max_rows, schema_profile)"
+                    .to_string(),
+            },
+            ActionType {
+                r#type: ACTION_STATS.to_string(),
+                description: "Return the most recent do_get's timing/byte counters".to_string(),
+            },
+            ActionType {
+                r#type: ACTION_RESET_STATS.to_string(),
+                description: "Clear the do_get timing/byte counters".to_string(),
+            },
+        ];
+
+        Ok(Response::new(Box::pin(stream::iter(actions.into_iter().map(Ok)))))
     }
 
     async fn do_exchange(
@@ -123,6 +626,8 @@ impl FlightService for FlightServiceImpl {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt::init();
+
     let addr = "[::1]:50051".parse()?;
 
     let n_columns = env::var("NUM_COLUMNS").ok()
@@ -133,9 +638,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .and_then(|v| usize::from_str(&v).ok())
         .unwrap_or(700_000);
 
+    let data = GeneratedData::generate(n_rows, n_columns, max_rows(), SchemaProfile::from_env()).unwrap();
+
     let service = FlightServiceImpl {
-        large_batch: generate_record_batch(n_columns, n_rows).unwrap(),
-        chunked_bathes: generate_record_batches(n_columns, n_rows).unwrap(),
+        state: Arc::new(RwLock::new(data)),
+        ingested: Arc::new(Mutex::new(Vec::new())),
+        stats: Arc::new(Mutex::new(DoGetStats::default())),
     };
 
     let svc = FlightServiceServer::new(service);
@@ -152,14 +660,35 @@ fn max_rows() -> usize {
         .unwrap_or(20_000)
 }
 
-fn generate_record_batches(n: usize, total_rows: usize) -> Result<Vec<RecordBatch>, arrow::error::ArrowError> {
+fn max_flight_data_size() -> usize {
+    env::var("MAX_FLIGHT_DATA_SIZE")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(2 * 1024 * 1024)
+}
+
+fn string_avg_len() -> usize {
+    env::var("STRING_AVG_LEN")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(16)
+}
+
+fn dictionary_cardinality() -> usize {
+    env::var("DICTIONARY_CARDINALITY")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(8)
+}
+
+fn generate_record_batches(n: usize, total_rows: usize, max_rows: usize, profile: SchemaProfile) -> Result<Vec<RecordBatch>, arrow::error::ArrowError> {
     let mut batches = Vec::new();
     let mut remaining_rows = total_rows;
 
     while remaining_rows > 0 {
-        let batch_rows = std::cmp::min(remaining_rows, max_rows());
-        
-        let batch = generate_record_batch(n, batch_rows)?;
+        let batch_rows = std::cmp::min(remaining_rows, max_rows);
+
+        let batch = generate_record_batch(n, batch_rows, profile)?;
         batches.push(batch);
 
         remaining_rows -= batch_rows;
@@ -168,10 +697,20 @@ fn generate_record_batches(n: usize, total_rows: usize) -> Result<Vec<RecordBatc
     Ok(batches)
 }
 
-fn generate_record_batch(n: usize, m: usize) -> Result<RecordBatch, arrow::error::ArrowError> {
+fn generate_record_batch(n: usize, m: usize, profile: SchemaProfile) -> Result<RecordBatch, arrow::error::ArrowError> {
+    match profile {
+        SchemaProfile::Int32 => generate_int32_batch(n, m),
+        SchemaProfile::Mixed => generate_mixed_batch(n, m, false),
+        SchemaProfile::WideNullable => generate_mixed_batch(n, m, true),
+        SchemaProfile::Strings => generate_strings_batch(n, m),
+        SchemaProfile::Dictionary => generate_dictionary_batch(n, m),
+    }
+}
+
+fn generate_int32_batch(n: usize, m: usize) -> Result<RecordBatch, arrow::error::ArrowError> {
     // Define the schema: n columns, each with Int32 data type
     let fields: Vec<Field> = (0..n)
-        .map(|i| Field::new(&format!("col{}", i), DataType::Int32, false))
+        .map(|i| Field::new(format!("col{}", i), DataType::Int32, false))
         .collect();
     let schema = Arc::new(Schema::new(fields));
 
@@ -185,4 +724,169 @@ fn generate_record_batch(n: usize, m: usize) -> Result<RecordBatch, arrow::error
 
     // Create the record batch with the generated schema and columns
     RecordBatch::try_new(schema, columns)
+}
+
+// Deterministic, fixed-seed PRNG (splitmix64) so repeated runs generate identical data while
+// still giving each (column, row) pair an independent-looking value.
+fn pseudo_random(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+fn cell_seed(col: usize, row: usize) -> u64 {
+    (col as u64).wrapping_mul(1_000_003).wrapping_add(row as u64)
+}
+
+// Interleaves Int32/Int64/Float64/Utf8/Boolean columns (by `col % 5`) so the encoder sees a
+// representative mix of Arrow types in one batch. When `nullable` is set every column is
+// nullable and every 7th row is null, for the "wide-nullable" profile.
+fn generate_mixed_batch(n: usize, m: usize, nullable: bool) -> Result<RecordBatch, arrow::error::ArrowError> {
+    let type_for = |col: usize| -> DataType {
+        match col % 5 {
+            0 => DataType::Int32,
+            1 => DataType::Int64,
+            2 => DataType::Float64,
+            3 => DataType::Utf8,
+            _ => DataType::Boolean,
+        }
+    };
+
+    let fields: Vec<Field> = (0..n)
+        .map(|col| Field::new(format!("col{}", col), type_for(col), nullable))
+        .collect();
+    let schema = Arc::new(Schema::new(fields));
+
+    let is_null = |row: usize| nullable && row.is_multiple_of(7);
+
+    let columns: Vec<ArrayRef> = (0..n)
+        .map(|col| -> ArrayRef {
+            match type_for(col) {
+                DataType::Int32 => {
+                    let mut builder = Int32Builder::with_capacity(m);
+                    for row in 0..m {
+                        if is_null(row) {
+                            builder.append_null();
+                        } else {
+                            builder.append_value(row as i32);
+                        }
+                    }
+                    Arc::new(builder.finish())
+                }
+                DataType::Int64 => {
+                    let mut builder = Int64Builder::with_capacity(m);
+                    for row in 0..m {
+                        if is_null(row) {
+                            builder.append_null();
+                        } else {
+                            builder.append_value(row as i64);
+                        }
+                    }
+                    Arc::new(builder.finish())
+                }
+                DataType::Float64 => {
+                    let mut builder = Float64Builder::with_capacity(m);
+                    for row in 0..m {
+                        if is_null(row) {
+                            builder.append_null();
+                        } else {
+                            builder.append_value(row as f64 + 0.5);
+                        }
+                    }
+                    Arc::new(builder.finish())
+                }
+                DataType::Utf8 => {
+                    let mut builder = StringBuilder::with_capacity(m, m * 8);
+                    for row in 0..m {
+                        if is_null(row) {
+                            builder.append_null();
+                        } else {
+                            builder.append_value(format!("row-{}", row));
+                        }
+                    }
+                    Arc::new(builder.finish())
+                }
+                _ => {
+                    let mut builder = BooleanBuilder::with_capacity(m);
+                    for row in 0..m {
+                        if is_null(row) {
+                            builder.append_null();
+                        } else {
+                            builder.append_value(row % 2 == 0);
+                        }
+                    }
+                    Arc::new(builder.finish())
+                }
+            }
+        })
+        .collect();
+
+    RecordBatch::try_new(schema, columns)
+}
+
+// Variable-length Utf8 columns whose per-row length is pseudo-random around STRING_AVG_LEN, to
+// stress the encoder's handling of non-uniform string sizes.
+fn generate_strings_batch(n: usize, m: usize) -> Result<RecordBatch, arrow::error::ArrowError> {
+    let avg_len = string_avg_len();
+
+    let fields: Vec<Field> = (0..n)
+        .map(|i| Field::new(format!("col{}", i), DataType::Utf8, false))
+        .collect();
+    let schema = Arc::new(Schema::new(fields));
+
+    const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+    let columns: Vec<ArrayRef> = (0..n)
+        .map(|col| {
+            let mut builder = StringBuilder::with_capacity(m, m * avg_len);
+            for row in 0..m {
+                let len_roll = pseudo_random(cell_seed(col, row));
+                let len = (avg_len / 2) + (len_roll % (avg_len as u64 + 1)) as usize;
+
+                let value: String = (0..len)
+                    .map(|i| {
+                        let idx = pseudo_random(cell_seed(col, row).wrapping_add(i as u64));
+                        ALPHABET[(idx % ALPHABET.len() as u64) as usize] as char
+                    })
+                    .collect();
+
+                builder.append_value(value);
+            }
+            Arc::new(builder.finish()) as ArrayRef
+        })
+        .collect();
+
+    RecordBatch::try_new(schema, columns)
+}
+
+// Dictionary(Int32, Utf8) columns drawn from a small, fixed pool of values, to exercise the
+// Flight encoder's dictionary tracker instead of always sending plain value arrays.
+fn generate_dictionary_batch(n: usize, m: usize) -> Result<RecordBatch, arrow::error::ArrowError> {
+    let cardinality = dictionary_cardinality().max(1);
+    let pool: Vec<String> = (0..cardinality).map(|i| format!("value-{}", i)).collect();
+
+    let fields: Vec<Field> = (0..n)
+        .map(|i| {
+            Field::new(
+                format!("col{}", i),
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                false,
+            )
+        })
+        .collect();
+    let schema = Arc::new(Schema::new(fields));
+
+    let columns: Vec<ArrayRef> = (0..n)
+        .map(|col| {
+            let mut builder = StringDictionaryBuilder::<Int32Type>::with_capacity(m, cardinality, m);
+            for row in 0..m {
+                let idx = (pseudo_random(cell_seed(col, row)) % cardinality as u64) as usize;
+                builder.append_value(&pool[idx]);
+            }
+            Arc::new(builder.finish()) as ArrayRef
+        })
+        .collect();
+
+    RecordBatch::try_new(schema, columns)
 }
\ No newline at end of file